@@ -1,11 +1,19 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
-use cw2::set_contract_version;
+use cosmwasm_std::{
+    coins, to_binary, Addr, BankMsg, Binary, Deps, DepsMut, Env, Event, MessageInfo, Response,
+    StdResult,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw_storage_plus::Bound;
+use semver::Version;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, GetAdminResponse, GetWriteResponse, InstantiateMsg, QueryMsg};
-use crate::state::{State, STATE};
+use crate::msg::{
+    ExecuteMsg, GetAdminResponse, GetDataAtResponse, GetHistoryResponse, GetOwnerResponse,
+    GetWriteResponse, HistoryEntry, InstantiateMsg, MigrateMsg, QueryMsg,
+};
+use crate::state::{State, WriteRecord, HISTORY, STATE};
 
 // basic info about smartcontract
 const CONTRACT_NAME: &str = "test_cosmos_writer";
@@ -21,6 +29,9 @@ pub fn instantiate(
     let state = State {
         data: msg.data,
         admins: vec![info.sender.clone()],
+        donation_denom: msg.donation_denom,
+        version: 0,
+        owner: info.sender.clone(),
     };
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     STATE.save(deps.storage, &state)?;
@@ -30,17 +41,114 @@ pub fn instantiate(
         .add_attribute("admin", info.sender))
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let stored = get_contract_version(deps.storage)?;
+    if stored.contract != CONTRACT_NAME {
+        return Err(ContractError::InvalidMigrationName {});
+    }
+
+    let stored_version: Version = stored
+        .version
+        .parse()
+        .map_err(|_| ContractError::InvalidMigrationVersion {})?;
+    let current_version: Version = CONTRACT_VERSION
+        .parse()
+        .map_err(|_| ContractError::InvalidMigrationVersion {})?;
+    if stored_version > current_version {
+        return Err(ContractError::InvalidMigrationVersion {});
+    }
+
+    // states stored before `owner`/`donation_denom` were introduced don't deserialize
+    // under the current `State` shape; fall back through the older shapes and backfill
+    // defaults, newest first.
+    if STATE.load(deps.storage).is_err() {
+        if let Ok(v2) = migrations::STATE_V2.load(deps.storage) {
+            let owner = v2
+                .admins
+                .first()
+                .cloned()
+                .unwrap_or_else(|| Addr::unchecked(""));
+            STATE.save(
+                deps.storage,
+                &State {
+                    data: v2.data,
+                    admins: v2.admins,
+                    donation_denom: v2.donation_denom,
+                    version: v2.version,
+                    owner,
+                },
+            )?;
+        } else {
+            let legacy = migrations::STATE_V1.load(deps.storage)?;
+            let owner = legacy
+                .admins
+                .first()
+                .cloned()
+                .unwrap_or_else(|| Addr::unchecked(""));
+            STATE.save(
+                deps.storage,
+                &State {
+                    data: legacy.data,
+                    admins: legacy.admins,
+                    donation_denom: String::new(),
+                    version: 0,
+                    owner,
+                },
+            )?;
+        }
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", stored_version.to_string())
+        .add_attribute("to_version", current_version.to_string()))
+}
+
+mod migrations {
+    use cosmwasm_std::Addr;
+    use cw_storage_plus::Item;
+    use schemars::JsonSchema;
+    use serde::{Deserialize, Serialize};
+
+    /// The `State` shape prior to the `donation_denom` field.
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+    pub struct StateV1 {
+        pub data: Vec<u8>,
+        pub admins: Vec<Addr>,
+    }
+
+    /// The `State` shape prior to the `owner` field.
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+    pub struct StateV2 {
+        pub data: Vec<u8>,
+        pub admins: Vec<Addr>,
+        pub donation_denom: String,
+        pub version: u64,
+    }
+
+    pub const STATE_V1: Item<StateV1> = Item::new("state");
+    pub const STATE_V2: Item<StateV2> = Item::new("state");
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Write { data } => execute::update(deps, info, data),
+        ExecuteMsg::Write { data } => execute::update(deps, env, info, data),
         ExecuteMsg::AddAdmin { admin } => execute::add_admin(deps, info, admin),
+        ExecuteMsg::AddMembers { admins } => execute::add_members(deps, info, admins),
         ExecuteMsg::RemoveAdmin { admin } => execute::remove_admin(deps, info, admin),
+        ExecuteMsg::Leave {} => execute::leave(deps, info),
+        ExecuteMsg::Donate {} => execute::donate(deps, info),
+        ExecuteMsg::TransferOwnership { new_owner } => {
+            execute::transfer_ownership(deps, info, new_owner)
+        }
     }
 }
 
@@ -49,17 +157,36 @@ pub mod execute {
 
     pub fn update(
         deps: DepsMut,
+        env: Env,
         info: MessageInfo,
         data: Vec<u8>,
     ) -> Result<Response, ContractError> {
-        STATE.update(deps.storage, |mut state| -> Result<_, ContractError> {
+        let data_len = data.len();
+        let state = STATE.update(deps.storage, |mut state| -> Result<_, ContractError> {
             if !state.admins.contains(&info.sender) {
                 return Err(ContractError::Unauthorized {});
             }
-            state.data = data;
+            state.data = data.clone();
+            state.version += 1;
             Ok(state)
         })?;
-        Ok(Response::new().add_attribute("action", "update"))
+        HISTORY.save(
+            deps.storage,
+            state.version,
+            &WriteRecord {
+                data,
+                author: info.sender.clone(),
+                height: env.block.height,
+            },
+        )?;
+
+        let event = Event::new("data_written")
+            .add_attribute("len", data_len.to_string())
+            .add_attribute("writer", info.sender);
+        Ok(Response::new()
+            .add_attribute("action", "update")
+            .add_attribute("version", state.version.to_string())
+            .add_event(event))
     }
 
     pub fn add_admin(
@@ -68,7 +195,7 @@ pub mod execute {
         admin: String,
     ) -> Result<Response, ContractError> {
         STATE.update(deps.storage, |mut state| -> Result<_, ContractError> {
-            if !state.admins.contains(&info.sender) {
+            if state.owner != info.sender {
                 return Err(ContractError::Unauthorized {});
             }
             let new_admin = deps.api.addr_validate(&admin)?;
@@ -77,9 +204,33 @@ pub mod execute {
             }
             Ok(state)
         })?;
+        let event = Event::new("admin_added").add_attribute("addr", admin.clone());
         Ok(Response::new()
             .add_attribute("action", "add_admin")
-            .add_attribute("new_admin", admin))
+            .add_attribute("new_admin", admin)
+            .add_event(event))
+    }
+
+    pub fn add_members(
+        deps: DepsMut,
+        info: MessageInfo,
+        admins: Vec<String>,
+    ) -> Result<Response, ContractError> {
+        STATE.update(deps.storage, |mut state| -> Result<_, ContractError> {
+            if state.owner != info.sender {
+                return Err(ContractError::Unauthorized {});
+            }
+            for admin in &admins {
+                let new_admin = deps.api.addr_validate(admin)?;
+                if !state.admins.contains(&new_admin) {
+                    state.admins.push(new_admin);
+                }
+            }
+            Ok(state)
+        })?;
+        Ok(Response::new()
+            .add_attribute("action", "add_members")
+            .add_attribute("new_admins", admins.join(",")))
     }
 
     pub fn remove_admin(
@@ -88,16 +239,82 @@ pub mod execute {
         admin: String,
     ) -> Result<Response, ContractError> {
         STATE.update(deps.storage, |mut state| -> Result<_, ContractError> {
-            if !state.admins.contains(&info.sender) {
+            if state.owner != info.sender {
                 return Err(ContractError::Unauthorized {});
             }
             let remove_admin = deps.api.addr_validate(&admin)?;
             state.admins.retain(|x| x != &remove_admin); // Remove the specified admin from the list via inplace op
             Ok(state)
         })?;
+        let event = Event::new("admin_removed").add_attribute("addr", admin.clone());
         Ok(Response::new()
             .add_attribute("action", "remove_admin")
-            .add_attribute("removed_admin", admin))
+            .add_attribute("removed_admin", admin)
+            .add_event(event))
+    }
+
+    pub fn leave(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+        STATE.update(deps.storage, |mut state| -> Result<_, ContractError> {
+            if !state.admins.contains(&info.sender) {
+                return Err(ContractError::Unauthorized {});
+            }
+            state.admins.retain(|x| x != &info.sender);
+            Ok(state)
+        })?;
+        Ok(Response::new()
+            .add_attribute("action", "leave")
+            .add_attribute("admin", info.sender))
+    }
+
+    pub fn donate(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.admins.is_empty() {
+            return Err(ContractError::NoAdmins {});
+        }
+
+        let sent = info
+            .funds
+            .iter()
+            .find(|coin| coin.denom == state.donation_denom)
+            .ok_or(ContractError::NoDonationSent {})?;
+
+        // the remainder of the integer division is left in the contract
+        let share = sent.amount.u128() / state.admins.len() as u128;
+        // a zero share would produce an invalid zero-amount bank send; skip it instead
+        let messages: Vec<BankMsg> = if share == 0 {
+            vec![]
+        } else {
+            state
+                .admins
+                .iter()
+                .map(|admin| BankMsg::Send {
+                    to_address: admin.to_string(),
+                    amount: coins(share, &state.donation_denom),
+                })
+                .collect()
+        };
+
+        Ok(Response::new()
+            .add_messages(messages)
+            .add_attribute("action", "donate")
+            .add_attribute("per_admin", share.to_string()))
+    }
+
+    pub fn transfer_ownership(
+        deps: DepsMut,
+        info: MessageInfo,
+        new_owner: String,
+    ) -> Result<Response, ContractError> {
+        STATE.update(deps.storage, |mut state| -> Result<_, ContractError> {
+            if state.owner != info.sender {
+                return Err(ContractError::Unauthorized {});
+            }
+            state.owner = deps.api.addr_validate(&new_owner)?;
+            Ok(state)
+        })?;
+        Ok(Response::new()
+            .add_attribute("action", "transfer_ownership")
+            .add_attribute("new_owner", new_owner))
     }
 }
 
@@ -106,9 +323,17 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::GetData {} => to_binary(&query::data(deps)?), // instantiated for tests purely
         QueryMsg::GetAdmins {} => to_binary(&query::admins(deps)?),
+        QueryMsg::GetDataAt { version } => to_binary(&query::data_at(deps, version)?),
+        QueryMsg::GetHistory { start_after, limit } => {
+            to_binary(&query::history(deps, start_after, limit)?)
+        }
+        QueryMsg::GetOwner {} => to_binary(&query::owner(deps)?),
     }
 }
 
+const DEFAULT_HISTORY_LIMIT: u32 = 10;
+const MAX_HISTORY_LIMIT: u32 = 30;
+
 pub mod query {
     use super::*;
 
@@ -125,6 +350,48 @@ pub mod query {
             admins: state.admins,
         })
     }
+
+    /// Retrieving the owner account
+    pub fn owner(deps: Deps) -> StdResult<GetOwnerResponse> {
+        let state = STATE.load(deps.storage)?;
+        Ok(GetOwnerResponse { owner: state.owner })
+    }
+
+    /// Retrieving the data written at a specific version
+    pub fn data_at(deps: Deps, version: u64) -> StdResult<GetDataAtResponse> {
+        let record = HISTORY.load(deps.storage, version)?;
+        Ok(GetDataAtResponse {
+            data: record.data,
+            author: record.author,
+            height: record.height,
+        })
+    }
+
+    /// Paginating over the full write history, oldest version first
+    pub fn history(
+        deps: Deps,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<GetHistoryResponse> {
+        let limit = limit.unwrap_or(DEFAULT_HISTORY_LIMIT).min(MAX_HISTORY_LIMIT) as usize;
+        let min = start_after.map(Bound::exclusive);
+
+        let entries = HISTORY
+            .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let (version, record) = item?;
+                Ok(HistoryEntry {
+                    version,
+                    data: record.data,
+                    author: record.author,
+                    height: record.height,
+                })
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Ok(GetHistoryResponse { entries })
+    }
 }
 
 #[cfg(test)]
@@ -137,7 +404,7 @@ mod tests {
     fn proper_initialization() {
         let mut deps = mock_dependencies();
 
-        let msg = InstantiateMsg { data: vec![17] };
+        let msg = InstantiateMsg { data: vec![17], donation_denom: "token".to_string() };
         let info = mock_info("creator", &coins(1000, "token"));
 
         // we can just call .unwrap() to assert this was a success
@@ -154,7 +421,7 @@ mod tests {
     fn unauthorized_write_attempt() {
         let mut deps = mock_dependencies();
 
-        let msg = InstantiateMsg { data: vec![17] };
+        let msg = InstantiateMsg { data: vec![17], donation_denom: "token".to_string() };
         let info = mock_info("creator", &coins(2, "token"));
         let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
@@ -173,7 +440,7 @@ mod tests {
     fn allowed_write() {
         // setting up a test env
         let mut deps = mock_dependencies();
-        let msg = InstantiateMsg { data: vec![17] };
+        let msg = InstantiateMsg { data: vec![17], donation_denom: "token".to_string() };
         let info = mock_info("creator", &coins(2, "token"));
         instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
@@ -201,7 +468,7 @@ mod tests {
     fn add_admins() {
         // setting up a test env
         let mut deps = mock_dependencies();
-        let msg = InstantiateMsg { data: vec![17] };
+        let msg = InstantiateMsg { data: vec![17], donation_denom: "token".to_string() };
         let info = mock_info("creator", &coins(2, "token"));
         instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
@@ -236,7 +503,7 @@ mod tests {
     fn list_and_remove_admins() {
         // setting up a test env
         let mut deps = mock_dependencies();
-        let msg = InstantiateMsg { data: vec![17] };
+        let msg = InstantiateMsg { data: vec![17], donation_denom: "token".to_string() };
         let info = mock_info("creator", &coins(2, "token"));
         instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
@@ -267,4 +534,235 @@ mod tests {
         assert_eq!(list.admins, expected_admins);
         dbg!(list.admins);
     }
+
+    #[test]
+    fn add_members_batches_and_dedupes() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg { data: vec![17], donation_denom: "token".to_string() };
+        let info = mock_info("creator", &coins(2, "token"));
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // adding a batch of admins, including one already present
+        let auth_info = mock_info("creator", &coins(2, "token"));
+        let msg = ExecuteMsg::AddMembers {
+            admins: vec![
+                "creator".to_string(),
+                "user_one".to_string(),
+                "user_two".to_string(),
+            ],
+        };
+        execute(deps.as_mut(), mock_env(), auth_info, msg).unwrap();
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetAdmins {}).unwrap();
+        let list: GetAdminResponse = from_binary(&res).unwrap();
+        let expected_admins = vec![
+            Addr::unchecked("creator"),
+            Addr::unchecked("user_one"),
+            Addr::unchecked("user_two"),
+        ];
+        assert_eq!(list.admins, expected_admins);
+    }
+
+    #[test]
+    fn leave_removes_sender_without_another_admin() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg { data: vec![17], donation_denom: "token".to_string() };
+        let info = mock_info("creator", &coins(2, "token"));
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let auth_info = mock_info("creator", &coins(2, "token"));
+        let msg = ExecuteMsg::AddAdmin {
+            admin: "new_user".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), auth_info, msg).unwrap();
+
+        // `new_user` can remove themselves without `creator`'s cooperation
+        let leaving_info = mock_info("new_user", &coins(2, "token"));
+        let msg = ExecuteMsg::Leave {};
+        execute(deps.as_mut(), mock_env(), leaving_info, msg).unwrap();
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetAdmins {}).unwrap();
+        let list: GetAdminResponse = from_binary(&res).unwrap();
+        assert_eq!(list.admins, vec![Addr::unchecked("creator")]);
+    }
+
+    #[test]
+    fn write_appends_history_and_keeps_latest_via_get_data() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg { data: vec![17], donation_denom: "token".to_string() };
+        let info = mock_info("creator", &coins(2, "token"));
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let auth_info = mock_info("creator", &coins(2, "token"));
+        let msg = ExecuteMsg::Write { data: vec![18] };
+        execute(deps.as_mut(), mock_env(), auth_info.clone(), msg).unwrap();
+
+        let msg = ExecuteMsg::Write { data: vec![19] };
+        execute(deps.as_mut(), mock_env(), auth_info, msg).unwrap();
+
+        // GetData still reflects only the latest revision
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetData {}).unwrap();
+        let value: GetWriteResponse = from_binary(&res).unwrap();
+        assert_eq!(vec![19], value.data);
+
+        // but both revisions are retrievable from history
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetDataAt { version: 1 }).unwrap();
+        let at_v1: GetDataAtResponse = from_binary(&res).unwrap();
+        assert_eq!(at_v1.data, vec![18]);
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetHistory {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let history: GetHistoryResponse = from_binary(&res).unwrap();
+        assert_eq!(history.entries.len(), 2);
+        assert_eq!(history.entries[0].version, 1);
+        assert_eq!(history.entries[1].version, 2);
+    }
+
+    #[test]
+    fn non_owner_admin_can_write_but_not_manage_admins() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg { data: vec![17], donation_denom: "token".to_string() };
+        let info = mock_info("creator", &coins(2, "token"));
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // `creator` (the owner) grants `new_user` write access
+        let owner_info = mock_info("creator", &coins(2, "token"));
+        let msg = ExecuteMsg::AddAdmin {
+            admin: "new_user".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), owner_info, msg).unwrap();
+
+        // `new_user` can write...
+        let admin_info = mock_info("new_user", &coins(2, "token"));
+        let msg = ExecuteMsg::Write { data: vec![20] };
+        execute(deps.as_mut(), mock_env(), admin_info.clone(), msg).unwrap();
+
+        // ...but cannot add or remove other admins, since that is owner-only
+        let msg = ExecuteMsg::AddAdmin {
+            admin: "another_user".to_string(),
+        };
+        let res = execute(deps.as_mut(), mock_env(), admin_info.clone(), msg);
+        match res {
+            Err(ContractError::Unauthorized {}) => {}
+            _ => panic!("Must return unauthorized error"),
+        }
+
+        let msg = ExecuteMsg::RemoveAdmin {
+            admin: "creator".to_string(),
+        };
+        let res = execute(deps.as_mut(), mock_env(), admin_info, msg);
+        match res {
+            Err(ContractError::Unauthorized {}) => {}
+            _ => panic!("Must return unauthorized error"),
+        }
+    }
+
+    #[test]
+    fn transfer_ownership_moves_admin_management_rights() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg { data: vec![17], donation_denom: "token".to_string() };
+        let info = mock_info("creator", &coins(2, "token"));
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let owner_info = mock_info("creator", &coins(2, "token"));
+        let msg = ExecuteMsg::TransferOwnership {
+            new_owner: "new_owner".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), owner_info.clone(), msg).unwrap();
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetOwner {}).unwrap();
+        let value: GetOwnerResponse = from_binary(&res).unwrap();
+        assert_eq!(value.owner, Addr::unchecked("new_owner"));
+
+        // `creator` no longer has owner-only rights
+        let msg = ExecuteMsg::AddAdmin {
+            admin: "new_user".to_string(),
+        };
+        let res = execute(deps.as_mut(), mock_env(), owner_info, msg);
+        match res {
+            Err(ContractError::Unauthorized {}) => {}
+            _ => panic!("Must return unauthorized error"),
+        }
+    }
+
+    #[test]
+    fn migrate_backfills_owner_onto_pre_owner_state() {
+        let mut deps = mock_dependencies();
+
+        // seed a state that predates the `owner` field, as chunk0-5 would have left it
+        migrations::STATE_V2
+            .save(
+                deps.as_mut().storage,
+                &migrations::StateV2 {
+                    data: vec![1, 2, 3],
+                    admins: vec![Addr::unchecked("creator"), Addr::unchecked("new_user")],
+                    donation_denom: "token".to_string(),
+                    version: 2,
+                },
+            )
+            .unwrap();
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.1.0").unwrap();
+
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+        let state = STATE.load(deps.as_ref().storage).unwrap();
+        assert_eq!(state.data, vec![1, 2, 3]);
+        assert_eq!(
+            state.admins,
+            vec![Addr::unchecked("creator"), Addr::unchecked("new_user")]
+        );
+        assert_eq!(state.donation_denom, "token");
+        assert_eq!(state.version, 2);
+        assert_eq!(state.owner, Addr::unchecked("creator"));
+    }
+
+    #[test]
+    fn migrate_backfills_donation_denom_and_owner_onto_v1_state() {
+        let mut deps = mock_dependencies();
+
+        // seed a state from before `donation_denom`/`version`/`owner` existed at all
+        migrations::STATE_V1
+            .save(
+                deps.as_mut().storage,
+                &migrations::StateV1 {
+                    data: vec![9],
+                    admins: vec![Addr::unchecked("creator")],
+                },
+            )
+            .unwrap();
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.1.0").unwrap();
+
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+        let state = STATE.load(deps.as_ref().storage).unwrap();
+        assert_eq!(state.data, vec![9]);
+        assert_eq!(state.admins, vec![Addr::unchecked("creator")]);
+        assert_eq!(state.donation_denom, "");
+        assert_eq!(state.version, 0);
+        assert_eq!(state.owner, Addr::unchecked("creator"));
+    }
+
+    #[test]
+    fn migrate_rejects_downgrade() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg { data: vec![17], donation_denom: "token".to_string() };
+        let info = mock_info("creator", &coins(2, "token"));
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // pretend the stored version is newer than the code being migrated to
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "999.0.0").unwrap();
+
+        let res = migrate(deps.as_mut(), mock_env(), MigrateMsg {});
+        match res {
+            Err(ContractError::InvalidMigrationVersion {}) => {}
+            _ => panic!("Must reject a downgrade"),
+        }
+    }
 }
@@ -0,0 +1,23 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("No admins configured")]
+    NoAdmins {},
+
+    #[error("No funds sent in the configured donation denom")]
+    NoDonationSent {},
+
+    #[error("Cannot migrate from a different contract")]
+    InvalidMigrationName {},
+
+    #[error("Cannot migrate to an older contract version")]
+    InvalidMigrationVersion {},
+}
@@ -68,6 +68,7 @@ mod tests {
 
     const USER: &str = "kujira1myl4t0y5eq3vjahjfm27re76xdr9zda4xerzd9";
     const ADMIN: &str = "kujira19n9ts2xpz5dz2a03808yjyj40d9e46ss8fgz2h";
+    const DONOR: &str = "kujira1hy3c4xspcugqd6nyrutffpf4mwg0c9jm66esyh";
     const NATIVE_DENOM: &str = "ukuji";
 
     fn mock_app() -> App {
@@ -83,6 +84,17 @@ mod tests {
                     }],
                 )
                 .unwrap();
+            router
+                .bank
+                .init_balance(
+                    storage,
+                    &Addr::unchecked(DONOR),
+                    vec![Coin {
+                        denom: NATIVE_DENOM.to_string(),
+                        amount: Uint128::new(4),
+                    }],
+                )
+                .unwrap();
         })
     }
 
@@ -90,7 +102,7 @@ mod tests {
         let mut app = mock_app();
         let cw_template_id = app.store_code(contract_template());
 
-        let msg = InstantiateMsg { data: vec![17] };
+        let msg = InstantiateMsg { data: vec![17], donation_denom: NATIVE_DENOM.to_string() };
         let cw_template_contract_addr = app
             .instantiate_contract(
                 cw_template_id,
@@ -98,7 +110,7 @@ mod tests {
                 &msg,
                 &[],
                 "test",
-                None,
+                Some(ADMIN.to_string()),
             )
             .unwrap();
 
@@ -119,6 +131,20 @@ mod tests {
             let msg = ExecuteMsg::Write { data: vec![17] };
             let cosmos_msg = cw_template_contract.call(msg).unwrap();
             let res = app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+
+            let event = res
+                .events
+                .iter()
+                .find(|e| e.ty == "wasm-data_written")
+                .expect("data_written event emitted");
+            assert_eq!(
+                event.attributes.iter().find(|a| a.key == "len").unwrap().value,
+                "1"
+            );
+            assert_eq!(
+                event.attributes.iter().find(|a| a.key == "writer").unwrap().value,
+                ADMIN
+            );
             dbg!(res);
         }
 
@@ -139,7 +165,17 @@ mod tests {
                 admin: USER.to_owned(),
             };
             let cosmos_msg = cw_template_contract.call(msg).unwrap();
-            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            let res = app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+
+            let event = res
+                .events
+                .iter()
+                .find(|e| e.ty == "wasm-admin_added")
+                .expect("admin_added event emitted");
+            assert_eq!(
+                event.attributes.iter().find(|a| a.key == "addr").unwrap().value,
+                USER
+            );
 
             let msg = ExecuteMsg::Write { data: vec![17, 18] };
             let cosmos_msg = cw_template_contract.call(msg).unwrap();
@@ -187,4 +223,150 @@ mod tests {
             assert_eq!(admins_list.admins, expected_admins);
         }
     }
+
+    mod donate {
+        use super::*;
+
+        use crate::msg::ExecuteMsg;
+
+        #[test]
+        fn donate_splits_funds_among_admins() {
+            let (mut app, cw_template_contract) = proper_instantiate();
+
+            let msg = ExecuteMsg::AddAdmin {
+                admin: USER.to_owned(),
+            };
+            let cosmos_msg = cw_template_contract.call(msg).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+
+            let msg = ExecuteMsg::Donate {};
+            app.execute_contract(
+                Addr::unchecked(DONOR),
+                cw_template_contract.addr(),
+                &msg,
+                &[Coin {
+                    denom: NATIVE_DENOM.to_string(),
+                    amount: Uint128::new(4),
+                }],
+            )
+            .unwrap();
+
+            // 4 ukuji split equally between the two admins, `ADMIN` and `USER`
+            let admin_balance = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+            assert_eq!(admin_balance.amount, Uint128::new(2));
+            let user_balance = app.wrap().query_balance(USER, NATIVE_DENOM).unwrap();
+            assert_eq!(user_balance.amount, Uint128::new(1 + 2));
+        }
+
+        #[test]
+        fn donate_without_matching_denom_fails() {
+            let (mut app, cw_template_contract) = proper_instantiate();
+
+            let msg = ExecuteMsg::Donate {};
+            app.execute_contract(
+                Addr::unchecked(USER),
+                cw_template_contract.addr(),
+                &msg,
+                &[],
+            )
+            .unwrap_err();
+        }
+    }
+
+    mod migrate {
+        use super::*;
+
+        use crate::msg::MigrateMsg;
+
+        #[test]
+        fn migrate_to_same_code_succeeds() {
+            let (mut app, cw_template_contract) = proper_instantiate();
+            let new_code_id = app.store_code(Box::new(
+                ContractWrapper::new(
+                    crate::contract::execute,
+                    crate::contract::instantiate,
+                    crate::contract::query,
+                )
+                .with_migrate(crate::contract::migrate),
+            ));
+
+            app.migrate_contract(
+                Addr::unchecked(ADMIN),
+                cw_template_contract.addr(),
+                &MigrateMsg {},
+                new_code_id,
+            )
+            .unwrap();
+        }
+    }
+
+    mod history {
+        use super::*;
+
+        use crate::msg::{ExecuteMsg, GetHistoryResponse, QueryMsg};
+
+        #[test]
+        fn get_history_paginates_oldest_first() {
+            let (mut app, cw_template_contract) = proper_instantiate();
+
+            for data in [vec![18], vec![19]] {
+                let msg = ExecuteMsg::Write { data };
+                let cosmos_msg = cw_template_contract.call(msg).unwrap();
+                app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+            }
+
+            let msg = QueryMsg::GetHistory {
+                start_after: None,
+                limit: Some(1),
+            };
+            let page: GetHistoryResponse = app
+                .wrap()
+                .query_wasm_smart(cw_template_contract.addr(), &msg)
+                .unwrap();
+            assert_eq!(page.entries.len(), 1);
+            assert_eq!(page.entries[0].version, 1);
+            assert_eq!(page.entries[0].data, vec![18]);
+        }
+    }
+
+    mod ownership {
+        use super::*;
+
+        use crate::msg::{ExecuteMsg, GetOwnerResponse};
+
+        #[test]
+        fn non_owner_admin_cannot_add_or_remove_admins() {
+            let (mut app, cw_template_contract) = proper_instantiate();
+
+            let msg = ExecuteMsg::AddAdmin {
+                admin: USER.to_owned(),
+            };
+            let cosmos_msg = cw_template_contract.call(msg).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+
+            let msg = ExecuteMsg::AddAdmin {
+                admin: "someone_else".to_string(),
+            };
+            let cosmos_msg = cw_template_contract.call(msg).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+        }
+
+        #[test]
+        fn transfer_ownership_updates_get_owner() {
+            let (mut app, cw_template_contract) = proper_instantiate();
+
+            let msg = ExecuteMsg::TransferOwnership {
+                new_owner: USER.to_owned(),
+            };
+            let cosmos_msg = cw_template_contract.call(msg).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+
+            let msg = crate::msg::QueryMsg::GetOwner {};
+            let owner: GetOwnerResponse = app
+                .wrap()
+                .query_wasm_smart(cw_template_contract.addr(), &msg)
+                .unwrap();
+            assert_eq!(owner.owner, Addr::unchecked(USER));
+        }
+    }
 }
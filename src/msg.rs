@@ -4,13 +4,21 @@ use cosmwasm_std::Addr;
 #[cw_serde]
 pub struct InstantiateMsg {
     pub data: Vec<u8>,
+    pub donation_denom: String,
 }
 
+#[cw_serde]
+pub struct MigrateMsg {}
+
 #[cw_serde]
 pub enum ExecuteMsg {
     Write { data: Vec<u8> },
     AddAdmin { admin: String },
+    AddMembers { admins: Vec<String> },
     RemoveAdmin { admin: String },
+    Leave {},
+    Donate {},
+    TransferOwnership { new_owner: String },
 }
 
 #[cw_serde]
@@ -23,6 +31,18 @@ pub enum QueryMsg {
     // GetAdmins returns accounts with admin privilleges
     #[returns(GetAdminResponse)]
     GetAdmins {},
+    // GetDataAt returns the data written at a specific version
+    #[returns(GetDataAtResponse)]
+    GetDataAt { version: u64 },
+    // GetHistory paginates over all versions ever written, oldest first
+    #[returns(GetHistoryResponse)]
+    GetHistory {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    // GetOwner returns the account with exclusive rights to manage the admin set
+    #[returns(GetOwnerResponse)]
+    GetOwner {},
 }
 
 // We define a custom struct for each query response
@@ -35,3 +55,28 @@ pub struct GetWriteResponse {
 pub struct GetAdminResponse {
     pub admins: Vec<Addr>,
 }
+
+#[cw_serde]
+pub struct GetDataAtResponse {
+    pub data: Vec<u8>,
+    pub author: Addr,
+    pub height: u64,
+}
+
+#[cw_serde]
+pub struct HistoryEntry {
+    pub version: u64,
+    pub data: Vec<u8>,
+    pub author: Addr,
+    pub height: u64,
+}
+
+#[cw_serde]
+pub struct GetHistoryResponse {
+    pub entries: Vec<HistoryEntry>,
+}
+
+#[cw_serde]
+pub struct GetOwnerResponse {
+    pub owner: Addr,
+}
@@ -2,12 +2,24 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use cosmwasm_std::Addr;
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct State {
     pub data: Vec<u8>,
     pub admins: Vec<Addr>,
+    pub donation_denom: String,
+    pub version: u64,
+    pub owner: Addr,
+}
+
+/// A single revision of `State::data`, recorded every time `Write` is executed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct WriteRecord {
+    pub data: Vec<u8>,
+    pub author: Addr,
+    pub height: u64,
 }
 
 pub const STATE: Item<State> = Item::new("state");
+pub const HISTORY: Map<u64, WriteRecord> = Map::new("history");